@@ -0,0 +1,73 @@
+//! A local SQLite audit log of archived members.
+//!
+//! Every successful archival is recorded so operators have a durable record of
+//! what the janitor touched and a rollback path via the `undo` command.
+
+use rusqlite::Connection;
+use std::path::Path;
+
+/// A row of the audit log.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub member_id: String,
+    pub email_address: String,
+    pub previous_status: String,
+    pub list_id: String,
+}
+
+/// A handle to the SQLite audit database.
+pub struct AuditLog {
+    conn: Connection,
+}
+
+impl AuditLog {
+    /// Opens the database at `path`, creating and migrating the table if needed.
+    pub fn open<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS archived_members (
+                member_id       TEXT NOT NULL,
+                email_address   TEXT NOT NULL,
+                previous_status TEXT NOT NULL,
+                list_id         TEXT NOT NULL,
+                archived_at     TEXT NOT NULL
+            );",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Records a single archived member.
+    pub fn record(
+        &self,
+        member_id: &str,
+        email_address: &str,
+        previous_status: &str,
+        list_id: &str,
+        archived_at: &str,
+    ) -> anyhow::Result<()> {
+        self.conn.execute(
+            "INSERT INTO archived_members
+                (member_id, email_address, previous_status, list_id, archived_at)
+                VALUES (?1, ?2, ?3, ?4, ?5)",
+            (member_id, email_address, previous_status, list_id, archived_at),
+        )?;
+        Ok(())
+    }
+
+    /// Reads back every recorded archival for the given list.
+    pub fn entries(&self, list_id: &str) -> anyhow::Result<Vec<AuditEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT member_id, email_address, previous_status, list_id
+                FROM archived_members WHERE list_id = ?1",
+        )?;
+        let rows = stmt.query_map([list_id], |row| {
+            Ok(AuditEntry {
+                member_id: row.get(0)?,
+                email_address: row.get(1)?,
+                previous_status: row.get(2)?,
+                list_id: row.get(3)?,
+            })
+        })?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+}
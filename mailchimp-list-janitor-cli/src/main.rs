@@ -1,6 +1,52 @@
-use clap::{Parser, Subcommand};
+mod audit;
+mod output;
+
+use audit::AuditLog;
+use chrono::{NaiveDate, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
 use futures_util::StreamExt;
 use mailchimp_list_janitor::Client;
+use mailchimp_list_janitor::client::RemovalMode;
+use mailchimp_list_janitor::export::{self, ExportFormat};
+use mailchimp_list_janitor::query::{Condition, Field, MemberQuery, Op};
+use output::{OutputFormat, Renderer};
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+#[value(rename_all = "lowercase")]
+enum ExportFormatArg {
+    Mbox,
+    Csv,
+}
+
+impl From<ExportFormatArg> for ExportFormat {
+    fn from(value: ExportFormatArg) -> Self {
+        match value {
+            ExportFormatArg::Mbox => ExportFormat::Mbox,
+            ExportFormatArg::Csv => ExportFormat::Csv,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+#[value(rename_all = "kebab-case")]
+enum RemovalModeArg {
+    /// `DELETE` the member; recoverable by re-adding them to the list.
+    Archive,
+    /// Trigger `delete-permanent`; irreversible and GDPR-compliant.
+    Permanent,
+}
+
+impl From<RemovalModeArg> for RemovalMode {
+    fn from(value: RemovalModeArg) -> Self {
+        match value {
+            RemovalModeArg::Archive => RemovalMode::Archive,
+            RemovalModeArg::Permanent => RemovalMode::PermanentDelete,
+        }
+    }
+}
 
 #[derive(Debug, Parser, Clone)]
 #[command(name = "mailchimp-list-janitor")]
@@ -19,42 +65,164 @@ struct Cli {
 #[derive(Debug, Subcommand, Clone)]
 enum Commands {
     #[command(about = "Archives all the unsubscribed users")]
-    Archive,
-    #[command(about = "Lists all the unsubscribed users")]
-    List,
+    Archive {
+        #[arg(long, help = "Archive members in a single Mailchimp batch operation")]
+        batched: bool,
+        #[arg(long, help = "Record every archival to a SQLite audit log at this path")]
+        db: Option<PathBuf>,
+    },
+    #[command(
+        about = "Destructively removes all the unsubscribed users",
+        long_about = "Destructively removes all the unsubscribed users. Unlike `archive`, \
+            which PATCHes members to `cleaned`, this deletes them: `--mode archive` issues a \
+            recoverable DELETE, while `--mode permanent` triggers the irreversible, \
+            GDPR-compliant delete-permanent action. The mode is always explicit so members \
+            are never permanently purged by accident."
+    )]
+    Delete {
+        #[arg(long, value_enum, help = "Which destructive operation to perform")]
+        mode: RemovalModeArg,
+    },
+    #[command(about = "Restores members recorded in the audit log back to subscribed")]
+    Undo {
+        #[arg(long, help = "Path to the SQLite audit log written by a previous archival run")]
+        db: PathBuf,
+    },
+    #[command(about = "Exports all members to a portable mbox or CSV snapshot")]
+    Export {
+        #[arg(long, value_enum, default_value_t = ExportFormatArg::Csv, help = "Export format")]
+        format: ExportFormatArg,
+    },
+    #[command(about = "Lists members matching the given criteria")]
+    List {
+        #[arg(long, default_value = "unsubscribed", help = "Member status to filter by")]
+        status: String,
+        #[arg(long, help = "Only members changed since this date (YYYY-MM-DD)")]
+        since: Option<NaiveDate>,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Csv, help = "Output format")]
+        output: OutputFormat,
+    },
 }
 
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
     let args = Cli::parse();
 
-    let client = Client::new(&args.base_url, &args.list_id, &args.api_key);
+    let client = Client::try_new(&args.base_url, &args.list_id, &args.api_key)?;
 
     match args.command {
-        Commands::Archive => {
-            let gen = client.move_unsubscribed_to_archive().await?;
+        Commands::Archive { batched, db } => {
+            // When auditing, capture each member's details up front so we can
+            // record the previous status alongside the archived id.
+            let (audit_log, mut members) = match db {
+                Some(path) => {
+                    let mut stream = client.fetch_unsubscribed().await.boxed();
+                    let mut members = HashMap::new();
+                    while let Some(res) = stream.next().await {
+                        match res {
+                            Ok(member) => {
+                                members.insert(member.id.clone(), member);
+                            }
+                            Err(err) => eprintln!("{err}"),
+                        }
+                    }
+                    (Some(AuditLog::open(path)?), members)
+                }
+                None => (None, HashMap::new()),
+            };
+
+            let mut stream = if batched {
+                client.move_unsubscribed_to_archive_batched().await?.boxed()
+            } else {
+                client.move_unsubscribed_to_archive().await?.boxed()
+            };
+
+            while let Some(res) = stream.next().await {
+                match res {
+                    Ok(id) => {
+                        if let Some(log) = &audit_log {
+                            if let Some(member) = members.remove(&id) {
+                                log.record(
+                                    &member.id,
+                                    &member.email_address,
+                                    member.status.as_str(),
+                                    &args.list_id,
+                                    &Utc::now().to_rfc3339(),
+                                )?;
+                            }
+                        }
+                        println!("Archived user with id {}", id);
+                    }
+                    Err(err) => eprintln!("{err}"),
+                }
+            }
+        }
+        Commands::Delete { mode } => {
+            let stream = client.remove_unsubscribed(mode.into()).await?;
+            stream
+                .for_each(|res| async move {
+                    match res {
+                        Ok(id) => println!("Removed user with id {}", id),
+                        Err(err) => eprintln!("{err}"),
+                    }
+                })
+                .await;
+        }
+        Commands::Undo { db } => {
+            let log = AuditLog::open(db)?;
+            let ids = log
+                .entries(&args.list_id)?
+                .into_iter()
+                .map(|entry| entry.member_id)
+                .collect::<Vec<_>>();
 
-            gen.for_each(|res| async move {
+            let stream = client.restore_members(ids).await;
+            stream
+                .for_each(|res| async move {
+                    match res {
+                        Ok(id) => println!("Restored user with id {}", id),
+                        Err(err) => eprintln!("{err}"),
+                    }
+                })
+                .await;
+        }
+        Commands::Export { format } => {
+            let mut stream = client.fetch_all_members().await.boxed();
+            let mut members = Vec::new();
+            while let Some(res) = stream.next().await {
                 match res {
-                    Ok(id) => println!("Archived user with id {}", id),
+                    Ok(member) => members.push(member),
                     Err(err) => eprintln!("{err}"),
                 }
-            })
-            .await;
+            }
+            export::export(&members, format.into(), io::stdout().lock())?;
         }
-        Commands::List => {
-            let gen = client.fetch_unsubscribed().await;
-            println!("id,email_address,full_name");
-            gen.for_each(|res| async move {
+        Commands::List {
+            status,
+            since,
+            output,
+        } => {
+            let mut conditions = vec![Condition {
+                field: Field::Status,
+                op: Op::Eq(status),
+            }];
+            if let Some(since) = since {
+                let since = since.and_hms_opt(0, 0, 0).unwrap().and_utc();
+                conditions.push(Condition {
+                    field: Field::SinceLastChanged,
+                    op: Op::Since(since),
+                });
+            }
+
+            let mut stream = client.fetch_members(MemberQuery::new(conditions)).await.boxed();
+            let mut renderer = Renderer::new(output)?;
+            while let Some(res) = stream.next().await {
                 match res {
-                    Ok(member) => println!(
-                        "{},{},\"{}\"",
-                        member.id, member.email_address, member.full_name
-                    ),
+                    Ok(member) => renderer.render(member)?,
                     Err(err) => eprintln!("{err}"),
                 }
-            })
-            .await;
+            }
+            renderer.finish()?;
         }
     }
 
@@ -0,0 +1,107 @@
+//! Renderers for the `list` command output.
+//!
+//! Streaming formats (`csv`, `ndjson`) write each member as it arrives, while
+//! the buffered formats (`table`, `json`) collect the whole stream before
+//! emitting so they can align columns or produce a single array.
+
+use clap::ValueEnum;
+use mailchimp_list_janitor::models::MailchimpMember;
+use std::io::{self, Write};
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+#[value(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Csv,
+    Table,
+    Json,
+    Ndjson,
+}
+
+/// Accumulates and renders members in the selected [`OutputFormat`].
+pub enum Renderer {
+    Csv(csv::Writer<io::Stdout>),
+    Ndjson,
+    Json(Vec<MailchimpMember>),
+    Table(Vec<MailchimpMember>),
+}
+
+impl Renderer {
+    pub fn new(format: OutputFormat) -> anyhow::Result<Self> {
+        Ok(match format {
+            OutputFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(io::stdout());
+                writer.write_record(["id", "email_address", "full_name"])?;
+                Renderer::Csv(writer)
+            }
+            OutputFormat::Ndjson => Renderer::Ndjson,
+            OutputFormat::Json => Renderer::Json(Vec::new()),
+            OutputFormat::Table => Renderer::Table(Vec::new()),
+        })
+    }
+
+    /// Renders a single member, either writing it immediately or buffering it.
+    pub fn render(&mut self, member: MailchimpMember) -> anyhow::Result<()> {
+        match self {
+            Renderer::Csv(writer) => {
+                writer.write_record([
+                    &member.id,
+                    &member.email_address,
+                    &member.full_name,
+                ])?;
+            }
+            Renderer::Ndjson => {
+                println!("{}", serde_json::to_string(&member)?);
+            }
+            Renderer::Json(buffer) | Renderer::Table(buffer) => buffer.push(member),
+        }
+        Ok(())
+    }
+
+    /// Flushes any buffered output.
+    pub fn finish(self) -> anyhow::Result<()> {
+        match self {
+            Renderer::Csv(mut writer) => writer.flush()?,
+            Renderer::Ndjson => {}
+            Renderer::Json(buffer) => {
+                println!("{}", serde_json::to_string_pretty(&buffer)?);
+            }
+            Renderer::Table(buffer) => render_table(&buffer),
+        }
+        Ok(())
+    }
+}
+
+fn render_table(members: &[MailchimpMember]) {
+    let headers = ["id", "email_address", "full_name"];
+    let mut widths = headers.map(str::len);
+    for member in members {
+        widths[0] = widths[0].max(member.id.len());
+        widths[1] = widths[1].max(member.email_address.len());
+        widths[2] = widths[2].max(member.full_name.len());
+    }
+
+    let mut out = io::stdout().lock();
+    let _ = writeln!(
+        out,
+        "{:<w0$}  {:<w1$}  {:<w2$}",
+        headers[0],
+        headers[1],
+        headers[2],
+        w0 = widths[0],
+        w1 = widths[1],
+        w2 = widths[2]
+    );
+    for member in members {
+        let _ = writeln!(
+            out,
+            "{:<w0$}  {:<w1$}  {:<w2$}",
+            member.id,
+            member.email_address,
+            member.full_name,
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        );
+    }
+}
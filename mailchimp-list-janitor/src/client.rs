@@ -1,14 +1,29 @@
 use crate::{
-    error::{ArchiveError, Error, FetchMemberError},
-    models::{MailchimpError, MailchimpListResponse, MailchimpMember},
+    error::{ArchiveError, BatchError, Error, FetchMemberError},
+    models::{
+        BatchOperation, BatchOperationResult, BatchRequest, BatchStatusResponse, MailchimpError,
+        MailchimpListResponse, MailchimpMember,
+    },
+    query::MemberQuery,
 };
 use async_gen::gen;
+use flate2::read::GzDecoder;
 use futures_core::Stream;
 use futures_util::StreamExt;
-use reqwest::{IntoUrl, Url};
-use std::{sync::Arc, time::Duration};
+use std::io::Read;
+use tar::Archive;
+use reqwest::{RequestBuilder, Response, StatusCode, Url, header::RETRY_AFTER};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 use tokio::task::JoinSet;
 
+/// Upper bound on how many times [`Client::run_batch`] polls a batch's status
+/// before giving up, so a batch stuck short of `finished` cannot loop forever.
+/// At the one-second poll interval this is roughly ten minutes.
+const MAX_BATCH_POLLS: u32 = 600;
+
 #[derive(Debug, Clone)]
 pub struct PageSize(usize);
 
@@ -27,6 +42,66 @@ impl Default for MaxConcurrency {
     }
 }
 
+/// Controls how the client retries rate-limited (`429`) and transient (`5xx`)
+/// responses before giving up.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sets the maximum number of retries before a request is given up on.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the base delay used to compute the exponential backoff and jitter.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the upper bound for the computed backoff delay.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Computes the backoff delay for a given (zero-based) attempt as
+    /// `min(max_delay, base_delay * 2^attempt)` plus uniform jitter in
+    /// `[0, base_delay)`.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jitter = self.base_delay.mul_f64(rand::random::<f64>());
+        exponential + jitter
+    }
+}
+
+/// Selects which of Mailchimp's two destructive member operations to perform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemovalMode {
+    /// `DELETE` the member; recoverable by re-adding them to the list.
+    Archive,
+    /// Trigger `delete-permanent`; irreversible and GDPR-compliant.
+    PermanentDelete,
+}
+
 #[derive(Clone, Debug)]
 pub struct Client {
     base_url: Url,
@@ -35,6 +110,18 @@ pub struct Client {
     http: reqwest::Client,
     max_concurrency: MaxConcurrency,
     page_size: PageSize,
+    retry_policy: RetryPolicy,
+}
+
+/// Extracts the delay requested by a `Retry-After` header, if present, parsing
+/// both the delay-seconds and the HTTP-date forms.
+fn retry_after(resp: &Response) -> Option<Duration> {
+    let value = resp.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let when = httpdate::parse_http_date(value).ok()?;
+    when.duration_since(SystemTime::now()).ok()
 }
 
 /// A Mailchimp client that can fetch and archive unsubscribed users.
@@ -43,35 +130,98 @@ impl Client {
     ///
     /// ## Panic
     ///
-    /// This function panics if the `base_url` is not a valid URL.
-    pub fn new<U: IntoUrl, S: Into<String>>(base_url: U, list_id: S, api_key: S) -> Arc<Self> {
+    /// This function panics if the `base_url` is not a valid URL. Use
+    /// [`Client::try_new`] if you want to handle an invalid URL gracefully.
+    pub fn new<U: AsRef<str>, S: Into<String>>(base_url: U, list_id: S, api_key: S) -> Arc<Self> {
+        Self::try_new(base_url, list_id, api_key).unwrap()
+    }
+
+    /// Initializes a new client, validating the `base_url` at construction time.
+    ///
+    /// Returns [`Error::InvalidUrl`] if the `base_url` cannot be parsed, so a
+    /// malformed region URL surfaces as a recoverable error rather than a panic.
+    pub fn try_new<U: AsRef<str>, S: Into<String>>(
+        base_url: U,
+        list_id: S,
+        api_key: S,
+    ) -> Result<Arc<Self>, Error> {
         let http = reqwest::Client::builder()
             .timeout(Duration::from_secs(10))
             .build()
             .unwrap();
-        Arc::new(Self {
-            base_url: base_url.into_url().unwrap(),
+        Ok(Arc::new(Self {
+            base_url: Url::parse(base_url.as_ref())?,
             list_id: list_id.into(),
             api_key: api_key.into(),
             http,
             max_concurrency: Default::default(),
             page_size: Default::default(),
-        })
+            retry_policy: Default::default(),
+        }))
     }
 
-    /// Fetches all the unsubscribed users from the list using a stream.
+    /// Returns a clone of the client configured with the given [`RetryPolicy`].
+    pub fn with_retry_policy(self: &Arc<Self>, retry_policy: RetryPolicy) -> Arc<Self> {
+        let mut client = (**self).clone();
+        client.retry_policy = retry_policy;
+        Arc::new(client)
+    }
+
+    /// Sends a request, retrying on `429` and `5xx` responses according to the
+    /// configured [`RetryPolicy`]. A `Retry-After` header, when present, takes
+    /// precedence over the computed exponential backoff.
+    async fn send_with_retry(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let mut attempt = 0;
+        loop {
+            // `try_clone` only fails for streaming bodies, which we never use.
+            let attempt_req = request
+                .try_clone()
+                .expect("request body should be cloneable for retries");
+
+            match attempt_req.send().await {
+                Ok(resp) => {
+                    let status = resp.status();
+                    let retryable =
+                        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+                    if retryable && attempt < self.retry_policy.max_retries {
+                        let delay =
+                            retry_after(&resp).unwrap_or_else(|| self.retry_policy.backoff(attempt));
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                        continue;
+                    }
+                    return Ok(resp);
+                }
+                Err(e) => {
+                    if attempt < self.retry_policy.max_retries {
+                        tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+                        attempt += 1;
+                        continue;
+                    }
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Fetches all the members matching a [`MemberQuery`] using a stream.
+    ///
+    /// Server-expressible conditions are combined onto the paginated request as
+    /// query parameters; the remaining ones (e.g.
+    /// [`Field::EmailContains`](crate::query::Field::EmailContains)) are
+    /// applied client-side as members arrive.
     ///
     /// ## Example
     ///
     /// ```no_run
     /// use futures_util::StreamExt;
     /// use mailchimp_list_janitor::client::Client;
-    /// use reqwest::Url;
+    /// use mailchimp_list_janitor::query::MemberQuery;
     ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let client = Client::new("https://us2.api.mailchimp.com", "list-id", "api-abcd1234");
-    ///     let stream = client.fetch_unsubscribed().await;
+    ///     let stream = client.fetch_members(MemberQuery::unsubscribed()).await;
     ///
     ///     stream.for_each(|member| async move {
     ///         match member {
@@ -87,23 +237,126 @@ impl Client {
     /// }
     ///
     /// ```
+    pub async fn fetch_members(
+        &self,
+        query: MemberQuery,
+    ) -> impl Stream<Item = Result<MailchimpMember, FetchMemberError>> + '_ {
+        let mut offset = 0;
+
+        let g = gen! {
+            loop {
+                let url = match self.base_url.join(&format!(
+                    "/3.0/lists/{}/members", self.list_id
+                )) {
+                    Ok(url) => url,
+                    Err(e) => {
+                        yield Err(FetchMemberError::InvalidUrl(e));
+                        break;
+                    }
+                };
+
+                let mut params = query.query_params();
+                params.push(("count".into(), self.page_size.0.to_string()));
+                params.push(("offset".into(), offset.to_string()));
+                params.push(("sort_field".into(), "timestamp_signup".into()));
+                params.push(("sort_dir".into(), "ASC".into()));
+
+                let resp = self.send_with_retry(
+                    self.http
+                        .get(url)
+                        .query(&params)
+                        .basic_auth("anystring", Some(&self.api_key))
+                ).await;
+
+                let resp = match resp {
+                    Ok(r) => r,
+                    Err(e) => {
+                        yield Err(FetchMemberError::Request(e));
+                        break;
+                    }
+                };
+
+                if resp.status().is_client_error() {
+                    match resp.json::<MailchimpError>().await {
+                        Ok(body) => yield Err(FetchMemberError::Mailchimp(body)),
+                        Err(e) => yield Err(FetchMemberError::Request(e)),
+                    }
+                    break;
+                }
+
+                let body: MailchimpListResponse = match resp.json().await {
+                    Ok(body) => body,
+                    Err(e) => {
+                        yield Err(FetchMemberError::Request(e));
+                        break;
+                    }
+                };
+
+                if body.members.is_empty() {
+                    break;
+                }
+
+                for member in body.members.into_iter() {
+                    if query.matches(&member) {
+                        yield Ok(member);
+                    }
+                }
+
+                offset += self.page_size.0;
+            }
+
+            ()
+        };
+
+        g.into_async_iter()
+    }
+
+    /// Fetches all the unsubscribed users from the list using a stream.
+    ///
+    /// This is a thin wrapper around [`Client::fetch_members`] with a
+    /// `status=unsubscribed` query.
     pub async fn fetch_unsubscribed(
         &self,
+    ) -> impl Stream<Item = Result<MailchimpMember, FetchMemberError>> + '_ {
+        self.fetch_members(MemberQuery::unsubscribed()).await
+    }
+
+    /// Fetches every member of the list, lazily, walking the pagination with
+    /// `count`/`offset` until `offset >= total_items`.
+    ///
+    /// Unlike [`Client::fetch_members`] this is driven by the `total_items`
+    /// count reported by Mailchimp rather than by a filtering query, so large
+    /// audiences are streamed page by page without being fully buffered.
+    pub async fn fetch_all_members(
+        &self,
     ) -> impl Stream<Item = Result<MailchimpMember, FetchMemberError>> + '_ {
         let mut offset = 0;
 
         let g = gen! {
             loop {
-                // Safe to unwrap here because the URL is mostly hardcoded (and the base URL is validated at construction time)
-                let url = self.base_url.join(&format!(
-                    "/3.0/lists/{}/members?status=unsubscribed&count={}&offset={}&sort_field=timestamp_signup&sort_dir=ASC",
-                    self.list_id, self.page_size.0, offset
-                )).unwrap();
-                let resp = self.http
-                  .get(url)
-                  .basic_auth("anystring", Some(&self.api_key))
-                  .send()
-                  .await;
+                let url = match self.base_url.join(&format!(
+                    "/3.0/lists/{}/members", self.list_id
+                )) {
+                    Ok(url) => url,
+                    Err(e) => {
+                        yield Err(FetchMemberError::InvalidUrl(e));
+                        break;
+                    }
+                };
+
+                let params = [
+                    ("count", self.page_size.0.to_string()),
+                    ("offset", offset.to_string()),
+                    ("sort_field", "timestamp_signup".to_string()),
+                    ("sort_dir", "ASC".to_string()),
+                ];
+
+                let resp = self.send_with_retry(
+                    self.http
+                        .get(url)
+                        .query(&params)
+                        .basic_auth("anystring", Some(&self.api_key))
+                ).await;
 
                 let resp = match resp {
                     Ok(r) => r,
@@ -114,13 +367,21 @@ impl Client {
                 };
 
                 if resp.status().is_client_error() {
-                    // Safe to unwrap because we know the response is an error (assuming Mailchimp doesn't change their error type)
-                    let body: MailchimpError = resp.json().await.unwrap();
-                    yield Err(FetchMemberError::Mailchimp(body));
+                    match resp.json::<MailchimpError>().await {
+                        Ok(body) => yield Err(FetchMemberError::Mailchimp(body)),
+                        Err(e) => yield Err(FetchMemberError::Request(e)),
+                    }
                     break;
                 }
 
-                let body: MailchimpListResponse = resp.json().await.unwrap();
+                let body: MailchimpListResponse = match resp.json().await {
+                    Ok(body) => body,
+                    Err(e) => {
+                        yield Err(FetchMemberError::Request(e));
+                        break;
+                    }
+                };
+                let total_items = body.total_items;
 
                 if body.members.is_empty() {
                     break;
@@ -131,6 +392,9 @@ impl Client {
                 }
 
                 offset += self.page_size.0;
+                if offset as u64 >= total_items {
+                    break;
+                }
             }
 
             ()
@@ -161,19 +425,76 @@ impl Client {
     /// }
     /// ```
     pub async fn archive_unsubscribed(&self, id: &str) -> Result<String, ArchiveError> {
-        // Safe to unwrap here because the URL is mostly hardcoded (and the base URL is validated at construction time)
+        self.set_member_status(id, "cleaned").await
+    }
+
+    /// Sets a member's status by PATCHing the member resource.
+    ///
+    /// This is the generalized primitive behind [`Client::archive_unsubscribed`]
+    /// (`cleaned`) and the `undo` flow (`subscribed`).
+    pub async fn set_member_status(
+        &self,
+        id: &str,
+        status: &str,
+    ) -> Result<String, ArchiveError> {
         let url = self
             .base_url
             .join(&format!("/3.0/lists/{}/members/{}", self.list_id, id))
-            .unwrap();
+            .map_err(|e| ArchiveError::InvalidUrl(id.to_string(), e))?;
 
         let resp = self
-            .http
-            .patch(url)
-            .basic_auth("anystring", Some(&self.api_key))
-            .header("Content-Type", "application/json")
-            .body("{\"status\":\"cleaned\"}")
-            .send()
+            .send_with_retry(
+                self.http
+                    .patch(url)
+                    .basic_auth("anystring", Some(&self.api_key))
+                    .header("Content-Type", "application/json")
+                    .body(format!("{{\"status\":\"{status}\"}}")),
+            )
+            .await
+            .map_err(|e| ArchiveError::Request(id.to_string(), e))?;
+
+        if resp.status().is_client_error() {
+            let body: MailchimpError = resp
+                .json()
+                .await
+                .map_err(|e| ArchiveError::Request(id.to_string(), e))?;
+            return Err(ArchiveError::Mailchimp(id.to_string(), body));
+        }
+
+        Ok(id.to_string())
+    }
+
+    /// Removes a member from the list, choosing between the two destructive
+    /// operations Mailchimp offers.
+    ///
+    /// [`RemovalMode::Archive`] issues a `DELETE` on the member, which is
+    /// recoverable; [`RemovalMode::PermanentDelete`] triggers the
+    /// `delete-permanent` action, which is irreversible (GDPR). The mode is
+    /// always explicit so the janitor never permanently purges by accident.
+    pub async fn remove_member(
+        &self,
+        id: &str,
+        mode: RemovalMode,
+    ) -> Result<String, ArchiveError> {
+        let path = match mode {
+            RemovalMode::Archive => format!("/3.0/lists/{}/members/{}", self.list_id, id),
+            RemovalMode::PermanentDelete => format!(
+                "/3.0/lists/{}/members/{}/actions/delete-permanent",
+                self.list_id, id
+            ),
+        };
+        let url = self
+            .base_url
+            .join(&path)
+            .map_err(|e| ArchiveError::InvalidUrl(id.to_string(), e))?;
+
+        let request = match mode {
+            RemovalMode::Archive => self.http.delete(url),
+            RemovalMode::PermanentDelete => self.http.post(url),
+        };
+
+        let resp = self
+            .send_with_retry(request.basic_auth("anystring", Some(&self.api_key)))
             .await
             .map_err(|e| ArchiveError::Request(id.to_string(), e))?;
 
@@ -276,6 +597,250 @@ impl Client {
 
         Ok(g.into_async_iter())
     }
+
+    /// Removes all the unsubscribed users from the list using the chosen
+    /// [`RemovalMode`], streaming one result per member.
+    ///
+    /// Like [`Client::move_unsubscribed_to_archive`] the ids are collected
+    /// eagerly so Mailchimp's pagination is not disturbed while members are
+    /// being removed. Unlike it, the members are removed destructively —
+    /// [`RemovalMode::Archive`] issues a recoverable `DELETE`, while
+    /// [`RemovalMode::PermanentDelete`] triggers the irreversible
+    /// `delete-permanent` action — rather than being PATCHed to `cleaned`.
+    pub async fn remove_unsubscribed(
+        self: Arc<Self>,
+        mode: RemovalMode,
+    ) -> Result<impl Stream<Item = Result<String, Error>>, Error> {
+        let mut unsubcribed_ids = self.clone().get_unsubscribed_ids().await?;
+
+        let g = gen! {
+          let concurrency = self.max_concurrency.0.min(unsubcribed_ids.len());
+
+          let mut tasks: JoinSet<Result<String, Error>> = JoinSet::new();
+          while let Some(id) = unsubcribed_ids.pop() {
+              let this = self.clone();
+              tasks.spawn(async move {
+                  this.remove_member(&id, mode).await.map_err(|e| e.into())
+              });
+
+              if tasks.len() >= concurrency {
+                  break;
+              }
+          }
+
+          while let Some(res) = tasks.join_next().await {
+              match res {
+                Ok(r) => yield r,
+                Err(err) => yield Err(Error::Join(err)),
+              }
+
+              let this = self.clone();
+              if let Some(id) = unsubcribed_ids.pop() {
+                  tasks.spawn(async move {
+                      this.remove_member(&id, mode).await.map_err(|e| e.into())
+                  });
+              }
+          }
+
+          ()
+        };
+
+        Ok(g.into_async_iter())
+    }
+
+    /// Archives all the unsubscribed users in a single Mailchimp [Batch
+    /// operation](https://mailchimp.com/developer/marketing/api/batch-operations/),
+    /// rather than one PATCH per member.
+    ///
+    /// This submits one `operations` entry per unsubscribed member, polls the
+    /// batch until it is `finished`, then downloads and parses the gzipped
+    /// results archive, streaming back one entry per operation mapped to its
+    /// member id. For large lists this trades thousands of small requests for a
+    /// handful of batch submissions.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use mailchimp_list_janitor::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new("https://us2.api.mailchimp.com", "list-id", "api-abcd1234");
+    ///     let stream = client.move_unsubscribed_to_archive_batched().await?;
+    ///
+    ///     stream
+    ///         .for_each(|res| async move {
+    ///             match res {
+    ///                 Ok(id) => println!("Archived user with id {}", id),
+    ///                 Err(err) => eprintln!("{err}"),
+    ///             }
+    ///         })
+    ///         .await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn move_unsubscribed_to_archive_batched(
+        self: Arc<Self>,
+    ) -> Result<impl Stream<Item = Result<String, Error>>, Error> {
+        let unsubscribed_ids = self.clone().get_unsubscribed_ids().await?;
+
+        let operations = unsubscribed_ids
+            .into_iter()
+            .map(|id| BatchOperation {
+                method: "PATCH".into(),
+                path: format!("/3.0/lists/{}/members/{}", self.list_id, id),
+                body: "{\"status\":\"cleaned\"}".into(),
+                operation_id: id,
+            })
+            .collect::<Vec<_>>();
+
+        let results = self.run_batch(BatchRequest { operations }).await?;
+
+        let g = gen! {
+            for result in results {
+                if (200..300).contains(&result.status_code) {
+                    yield Ok(result.operation_id);
+                } else {
+                    yield Err(Error::Batch(BatchError::Operation(
+                        result.operation_id,
+                        result.status_code,
+                    )));
+                }
+            }
+
+            ()
+        };
+
+        Ok(g.into_async_iter())
+    }
+
+    /// Restores the given members to `subscribed`, streaming the results the
+    /// same way as [`Client::move_unsubscribed_to_archive`].
+    ///
+    /// This is the inverse operation used by the `undo` flow to roll back a
+    /// previous archival run recorded in the audit log.
+    pub async fn restore_members(
+        self: Arc<Self>,
+        ids: Vec<String>,
+    ) -> impl Stream<Item = Result<String, Error>> {
+        let mut ids = ids;
+
+        let g = gen! {
+            let concurrency = self.max_concurrency.0.min(ids.len());
+
+            let mut tasks: JoinSet<Result<String, Error>> = JoinSet::new();
+            while let Some(id) = ids.pop() {
+                let this = self.clone();
+                tasks.spawn(async move {
+                    this.set_member_status(&id, "subscribed").await.map_err(|e| e.into())
+                });
+
+                if tasks.len() >= concurrency {
+                    break;
+                }
+            }
+
+            while let Some(res) = tasks.join_next().await {
+                match res {
+                    Ok(r) => yield r,
+                    Err(err) => yield Err(Error::Join(err)),
+                }
+
+                let this = self.clone();
+                if let Some(id) = ids.pop() {
+                    tasks.spawn(async move {
+                        this.set_member_status(&id, "subscribed").await.map_err(|e| e.into())
+                    });
+                }
+            }
+
+            ()
+        };
+
+        g.into_async_iter()
+    }
+
+    /// Submits a batch request, polls it to completion and returns the parsed
+    /// per-operation results.
+    async fn run_batch(
+        &self,
+        request: BatchRequest,
+    ) -> Result<Vec<BatchOperationResult>, BatchError> {
+        let batches_url = self.base_url.join("/3.0/batches")?;
+        let resp = self
+            .send_with_retry(
+                self.http
+                    .post(batches_url)
+                    .basic_auth("anystring", Some(&self.api_key))
+                    .json(&request),
+            )
+            .await?;
+
+        if resp.status().is_client_error() {
+            let body: MailchimpError = resp.json().await?;
+            return Err(BatchError::Mailchimp(body));
+        }
+
+        let mut status: BatchStatusResponse = resp.json().await?;
+
+        // Bound the polling so a batch that never reaches `finished` (a stuck or
+        // malformed status) cannot loop forever.
+        let mut polls: u32 = 0;
+        while status.status != "finished" {
+            if polls >= MAX_BATCH_POLLS {
+                return Err(BatchError::PollTimeout(status.id, polls));
+            }
+            polls += 1;
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            let status_url = self
+                .base_url
+                .join(&format!("/3.0/batches/{}", status.id))?;
+            let resp = self
+                .send_with_retry(
+                    self.http
+                        .get(status_url)
+                        .basic_auth("anystring", Some(&self.api_key)),
+                )
+                .await?;
+            if resp.status().is_client_error() {
+                let body: MailchimpError = resp.json().await?;
+                return Err(BatchError::Mailchimp(body));
+            }
+            status = resp.json().await?;
+        }
+
+        self.download_batch_results(&status.response_body_url).await
+    }
+
+    /// Downloads the gzipped `tar` archive of batch results and decodes every
+    /// operation result it contains.
+    async fn download_batch_results(
+        &self,
+        response_body_url: &str,
+    ) -> Result<Vec<BatchOperationResult>, BatchError> {
+        let bytes = self
+            .send_with_retry(self.http.get(response_body_url))
+            .await?
+            .bytes()
+            .await?;
+
+        let mut archive = Archive::new(GzDecoder::new(bytes.as_ref()));
+        let mut results = vec![];
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents)?;
+            if contents.trim().is_empty() {
+                continue;
+            }
+            let parsed: Vec<BatchOperationResult> = serde_json::from_str(&contents)?;
+            results.extend(parsed);
+        }
+
+        Ok(results)
+    }
 }
 
 #[cfg(test)]
@@ -328,6 +893,7 @@ mod tests {
             http: reqwest::Client::new(),
             max_concurrency: MaxConcurrency::default(),
             page_size: PageSize(2),
+            retry_policy: RetryPolicy::default(),
         };
 
         Mock::given(method("GET"))
@@ -341,6 +907,7 @@ mod tests {
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(MailchimpListResponse {
                     members: vec![create_sample_member(1), create_sample_member(2)],
+                    total_items: 4,
                 }),
             )
             .expect(1)
@@ -358,6 +925,7 @@ mod tests {
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(MailchimpListResponse {
                     members: vec![create_sample_member(3), create_sample_member(4)],
+                    total_items: 4,
                 }),
             )
             .expect(1)
@@ -373,7 +941,10 @@ mod tests {
             .and(query_param("sort_dir", "ASC"))
             .and(header_exists("Authorization"))
             .respond_with(
-                ResponseTemplate::new(200).set_body_json(MailchimpListResponse { members: vec![] }),
+                ResponseTemplate::new(200).set_body_json(MailchimpListResponse {
+                    members: vec![],
+                    total_items: 4,
+                }),
             )
             .expect(1)
             .mount(&mock_server)
@@ -387,4 +958,68 @@ mod tests {
 
         assert_eq!(ids, vec!["1", "2", "3", "4"]);
     }
+
+    #[test]
+    fn test_backoff_grows_exponentially_within_jitter_bounds() {
+        let policy = RetryPolicy::default();
+        let base = policy.base_delay;
+
+        // For each attempt the delay is `base * 2^attempt` plus jitter in
+        // `[0, base)`, so it always lands in `[exponential, exponential + base)`.
+        for attempt in 0..4 {
+            let exponential = base * 2u32.pow(attempt);
+            let delay = policy.backoff(attempt);
+            assert!(delay >= exponential, "attempt {attempt}: below exponential");
+            assert!(delay < exponential + base, "attempt {attempt}: above jitter bound");
+        }
+    }
+
+    #[test]
+    fn test_backoff_is_capped_at_max_delay() {
+        let policy = RetryPolicy::default();
+        // A large attempt saturates the exponential term at `max_delay`; jitter
+        // is still added on top.
+        let delay = policy.backoff(30);
+        assert!(delay >= policy.max_delay);
+        assert!(delay < policy.max_delay + policy.base_delay);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_parses_delay_seconds() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "120"))
+            .mount(&mock_server)
+            .await;
+
+        let resp = reqwest::Client::new()
+            .get(mock_server.uri())
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(retry_after(&resp), Some(Duration::from_secs(120)));
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_parses_http_date() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "Wed, 21 Oct 2099 07:28:00 GMT"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let resp = reqwest::Client::new()
+            .get(mock_server.uri())
+            .send()
+            .await
+            .unwrap();
+
+        // The date is far in the future, so it resolves to a large positive delay.
+        let delay = retry_after(&resp).expect("HTTP-date should parse to a delay");
+        assert!(delay > Duration::from_secs(60 * 60 * 24));
+    }
 }
@@ -0,0 +1,54 @@
+use crate::models::MailchimpError;
+use tokio::task::JoinError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("{0}")]
+    Fetch(#[from] FetchMemberError),
+    #[error("{0}")]
+    Archive(#[from] ArchiveError),
+    #[error("{0}")]
+    Batch(#[from] BatchError),
+    #[error("Task cancelled: {0}")]
+    Join(#[from] JoinError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BatchError {
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("Request error: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Mailchimp error: {0}")]
+    Mailchimp(MailchimpError),
+    #[error("Could not read the batch results archive: {0}")]
+    Archive(#[from] std::io::Error),
+    #[error("Could not parse the batch results: {0}")]
+    Decode(#[from] serde_json::Error),
+    #[error("Operation for member {0} failed with status {1}")]
+    Operation(String, u16),
+    #[error("Batch {0} did not reach the `finished` state after {1} status polls")]
+    PollTimeout(String, u32),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FetchMemberError {
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("Request error: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Mailchimp error: {0}")]
+    Mailchimp(MailchimpError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ArchiveError {
+    #[error("Invalid URL while archiving user {0}: {1}")]
+    InvalidUrl(String, url::ParseError),
+    #[error("Request error while archiving user {0}: {1}")]
+    Request(String, reqwest::Error),
+    #[error("Mailchimp error while archiving user {0}: {1}")]
+    Mailchimp(String, MailchimpError),
+}
@@ -0,0 +1,105 @@
+//! Export members to portable snapshots before running destructive cleanup.
+//!
+//! Two formats are supported: an `mboxcl2`-style mailbox of synthetic messages
+//! (one per member) and a flat CSV whose columns are the core member fields
+//! plus the union of every member's `merge_fields` keys.
+
+use crate::models::MailchimpMember;
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+/// The snapshot format to produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Mbox,
+    Csv,
+}
+
+/// Exports `members` to `writer` in the given [`ExportFormat`].
+pub fn export<W: Write>(
+    members: &[MailchimpMember],
+    format: ExportFormat,
+    writer: W,
+) -> io::Result<()> {
+    match format {
+        ExportFormat::Mbox => export_mbox(members, writer),
+        ExportFormat::Csv => export_csv(members, writer),
+    }
+}
+
+/// Writes one synthetic `mboxcl2` message per member, carrying the member's
+/// core attributes in `X-Mailchimp-*` headers.
+pub fn export_mbox<W: Write>(members: &[MailchimpMember], mut writer: W) -> io::Result<()> {
+    for member in members {
+        let email = member.email_address.trim();
+        let tags = member
+            .tags
+            .iter()
+            .map(|tag| tag.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let body = format!("{} <{}>\n", member.full_name, email);
+
+        writeln!(writer, "From {} {}", email, member.timestamp_signup)?;
+        writeln!(writer, "From: {} <{}>", member.full_name, email)?;
+        writeln!(writer, "To: {}", member.list_id)?;
+        writeln!(writer, "Subject: Mailchimp member {}", member.id)?;
+        writeln!(writer, "X-Mailchimp-Email: {}", email)?;
+        writeln!(writer, "X-Mailchimp-Status: {}", member.status)?;
+        writeln!(writer, "X-Mailchimp-Tags: {}", tags)?;
+        writeln!(writer, "X-Mailchimp-Rating: {}", member.member_rating)?;
+        writeln!(writer, "Content-Length: {}", body.len())?;
+        writeln!(writer)?;
+        write!(writer, "{}", body)?;
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// Writes a CSV whose columns are the core member fields followed by the union
+/// of all `merge_fields` keys across `members`.
+pub fn export_csv<W: Write>(members: &[MailchimpMember], writer: W) -> io::Result<()> {
+    let mut merge_keys = BTreeSet::new();
+    for member in members {
+        merge_keys.extend(member.merge_fields.keys().cloned());
+    }
+    let merge_keys: Vec<String> = merge_keys.into_iter().collect();
+
+    let mut wtr = csv::Writer::from_writer(writer);
+    let to_io = |e: csv::Error| io::Error::new(io::ErrorKind::Other, e);
+
+    let mut header = vec![
+        "id".to_string(),
+        "email_address".to_string(),
+        "full_name".to_string(),
+        "status".to_string(),
+        "member_rating".to_string(),
+        "tags".to_string(),
+    ];
+    header.extend(merge_keys.iter().cloned());
+    wtr.write_record(&header).map_err(to_io)?;
+
+    for member in members {
+        let tags = member
+            .tags
+            .iter()
+            .map(|tag| tag.name.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut record = vec![
+            member.id.clone(),
+            member.email_address.clone(),
+            member.full_name.clone(),
+            member.status.to_string(),
+            member.member_rating.to_string(),
+            tags,
+        ];
+        for key in &merge_keys {
+            record.push(member.merge_fields.get(key).cloned().unwrap_or_default());
+        }
+        wtr.write_record(&record).map_err(to_io)?;
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
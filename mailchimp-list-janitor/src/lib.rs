@@ -26,6 +26,11 @@
 
 pub mod client;
 pub mod error;
+pub mod export;
 pub mod models;
+pub mod query;
+pub mod reconcile;
+pub mod report;
+pub mod rules;
 
 pub use client::Client;
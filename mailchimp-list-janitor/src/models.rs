@@ -1,6 +1,75 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{collections::BTreeMap, fmt::Display};
 
+/// The lifecycle status of a member.
+///
+/// The `Other` variant keeps deserialization forward-compatible with statuses
+/// Mailchimp may add in the future.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MemberStatus {
+    Subscribed,
+    Unsubscribed,
+    Cleaned,
+    Pending,
+    Transactional,
+    Archived,
+    Other(String),
+}
+
+impl MemberStatus {
+    /// The Mailchimp wire representation of the status.
+    pub fn as_str(&self) -> &str {
+        match self {
+            MemberStatus::Subscribed => "subscribed",
+            MemberStatus::Unsubscribed => "unsubscribed",
+            MemberStatus::Cleaned => "cleaned",
+            MemberStatus::Pending => "pending",
+            MemberStatus::Transactional => "transactional",
+            MemberStatus::Archived => "archived",
+            MemberStatus::Other(other) => other.as_str(),
+        }
+    }
+}
+
+impl Default for MemberStatus {
+    fn default() -> Self {
+        MemberStatus::Subscribed
+    }
+}
+
+impl From<&str> for MemberStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "subscribed" => MemberStatus::Subscribed,
+            "unsubscribed" => MemberStatus::Unsubscribed,
+            "cleaned" => MemberStatus::Cleaned,
+            "pending" => MemberStatus::Pending,
+            "transactional" => MemberStatus::Transactional,
+            "archived" => MemberStatus::Archived,
+            other => MemberStatus::Other(other.to_string()),
+        }
+    }
+}
+
+impl Display for MemberStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for MemberStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Ok(MemberStatus::from(raw.as_str()))
+    }
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct MailchimpMemberTag {
     pub id: u64,
@@ -56,7 +125,7 @@ pub struct MailchimpMember {
     pub full_name: String,
     pub web_id: u64,
     pub email_type: String,
-    pub status: String,
+    pub status: MemberStatus,
     pub unsubscribe_reason: String,
     pub consents_to_one_to_one_messaging: bool,
     #[serde(default)]
@@ -85,6 +154,40 @@ pub struct MailchimpMember {
 #[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct MailchimpListResponse {
     pub members: Vec<MailchimpMember>,
+    #[serde(default)]
+    pub total_items: u64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct BatchOperation {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+    pub operation_id: String,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct BatchRequest {
+    pub operations: Vec<BatchOperation>,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct BatchStatusResponse {
+    pub id: String,
+    pub status: String,
+    pub total_operations: u64,
+    pub finished_operations: u64,
+    pub errored_operations: u64,
+    #[serde(default)]
+    pub response_body_url: String,
+}
+
+/// A single operation result as stored in the gzipped batch results archive.
+#[derive(Deserialize, Clone, Debug)]
+pub struct BatchOperationResult {
+    pub status_code: u16,
+    pub operation_id: String,
+    pub response: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -109,3 +212,41 @@ impl Display for MailchimpError {
         )
     }
 }
+
+/// Builds a minimal, valid [`MailchimpMember`] for use in unit tests.
+///
+/// Callers override only the fields relevant to their test with struct-update
+/// syntax, e.g. `MailchimpMember { vip: true, ..sample_member() }`.
+#[cfg(test)]
+pub(crate) fn sample_member() -> MailchimpMember {
+    serde_json::from_str(
+        r#"{
+            "id": "id",
+            "email_address": "member@example.com",
+            "unique_email_id": "",
+            "contact_id": "",
+            "full_name": "",
+            "web_id": 0,
+            "email_type": "html",
+            "status": "subscribed",
+            "unsubscribe_reason": "",
+            "consents_to_one_to_one_messaging": false,
+            "stats": {"avg_open_rate": 0.0, "avg_click_rate": 0.0},
+            "ip_signup": "",
+            "timestamp_signup": "",
+            "ip_opt": "",
+            "timestamp_opt": "",
+            "member_rating": 0.0,
+            "last_changed": "",
+            "language": "en",
+            "vip": false,
+            "location": {"latitude":0.0,"longitude":0.0,"gmtoff":0,"dstoff":0,"country_code":"","timezone":"","region":""},
+            "last_note": null,
+            "source": "API",
+            "tags_count": 0,
+            "tags": [],
+            "list_id": "list-id"
+        }"#,
+    )
+    .unwrap()
+}
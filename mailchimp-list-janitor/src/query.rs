@@ -0,0 +1,160 @@
+//! A small typed DSL for selecting members to fetch from a list.
+//!
+//! A [`MemberQuery`] is a list of [`Condition`]s, each pairing a [`Field`] with
+//! an [`Op`]. Most conditions translate directly into Mailchimp query
+//! parameters; the ones that Mailchimp cannot express server-side (such as
+//! [`Field::EmailContains`]) are applied as a client-side filter.
+
+use crate::models::MailchimpMember;
+use chrono::{DateTime, Utc};
+
+/// A member attribute a [`Condition`] can be expressed against.
+#[derive(Debug, Clone)]
+pub enum Field {
+    Status,
+    SinceLastChanged,
+    BeforeLastChanged,
+    SinceTimestampOpt,
+    EmailContains,
+    Vip,
+}
+
+/// The operator applied to a [`Field`].
+#[derive(Debug, Clone)]
+pub enum Op {
+    Eq(String),
+    Since(DateTime<Utc>),
+    Before(DateTime<Utc>),
+    Contains(String),
+    Exists,
+    IsTrue,
+    IsFalse,
+}
+
+/// A single `field op` predicate.
+#[derive(Debug, Clone)]
+pub struct Condition {
+    pub field: Field,
+    pub op: Op,
+}
+
+/// A collection of [`Condition`]s combined onto a single paginated request.
+#[derive(Debug, Clone, Default)]
+pub struct MemberQuery {
+    pub conditions: Vec<Condition>,
+}
+
+impl MemberQuery {
+    /// Builds a query from a list of conditions.
+    pub fn new(conditions: Vec<Condition>) -> Self {
+        Self { conditions }
+    }
+
+    /// The canonical query selecting unsubscribed members.
+    pub fn unsubscribed() -> Self {
+        Self::new(vec![Condition {
+            field: Field::Status,
+            op: Op::Eq("unsubscribed".into()),
+        }])
+    }
+
+    /// Translates the conditions into Mailchimp query parameters. Conditions
+    /// that can only be evaluated client-side (see [`Self::matches`]) are
+    /// skipped here.
+    pub fn query_params(&self) -> Vec<(String, String)> {
+        let mut params = vec![];
+        for Condition { field, op } in &self.conditions {
+            match (field, op) {
+                (Field::Status, Op::Eq(value)) => {
+                    params.push(("status".into(), value.clone()));
+                }
+                (Field::SinceLastChanged, Op::Since(dt)) => {
+                    params.push(("since_last_changed".into(), dt.to_rfc3339()));
+                }
+                (Field::BeforeLastChanged, Op::Before(dt)) => {
+                    params.push(("before_last_changed".into(), dt.to_rfc3339()));
+                }
+                (Field::SinceTimestampOpt, Op::Since(dt)) => {
+                    params.push(("since_timestamp_opt".into(), dt.to_rfc3339()));
+                }
+                (Field::Vip, Op::IsTrue) => {
+                    params.push(("vip_only".into(), "true".into()));
+                }
+                // Client-side pairs are evaluated by `matches`; any other
+                // field/operator pair is not supported and is ignored.
+                _ => {}
+            }
+        }
+        params
+    }
+
+    /// Applies the conditions that Mailchimp cannot express server-side against
+    /// a fetched member.
+    pub fn matches(&self, member: &MailchimpMember) -> bool {
+        self.conditions.iter().all(|Condition { field, op }| {
+            match (field, op) {
+                (Field::EmailContains, Op::Contains(needle)) => {
+                    member.email_address.contains(needle.as_str())
+                }
+                (Field::Vip, Op::IsTrue) => member.vip,
+                (Field::Vip, Op::IsFalse) => !member.vip,
+                // Server-side conditions were already applied to the request;
+                // unsupported field/operator pairs are ignored.
+                _ => true,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn member_with_email(email: &str) -> MailchimpMember {
+        MailchimpMember {
+            email_address: email.to_string(),
+            ..crate::models::sample_member()
+        }
+    }
+
+    #[test]
+    fn test_query_params_are_server_side_only() {
+        let since = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let query = MemberQuery::new(vec![
+            Condition {
+                field: Field::Status,
+                op: Op::Eq("cleaned".into()),
+            },
+            Condition {
+                field: Field::SinceLastChanged,
+                op: Op::Since(since),
+            },
+            // Client-side only: must not leak into the request.
+            Condition {
+                field: Field::EmailContains,
+                op: Op::Contains("@example.com".into()),
+            },
+        ]);
+
+        let params = query.query_params();
+        assert_eq!(
+            params,
+            vec![
+                ("status".to_string(), "cleaned".to_string()),
+                ("since_last_changed".to_string(), since.to_rfc3339()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_email_contains_filters_post_fetch() {
+        let query = MemberQuery::new(vec![Condition {
+            field: Field::EmailContains,
+            op: Op::Contains("@example.com".into()),
+        }]);
+
+        assert!(query.matches(&member_with_email("jane@example.com")));
+        assert!(!query.matches(&member_with_email("jane@other.org")));
+    }
+}
@@ -0,0 +1,248 @@
+//! Reconcile inbound unsubscribe/complaint feedback against the audience.
+//!
+//! Feedback messages (bounces, complaints, unsubscribe confirmations) carry a
+//! `List-Unsubscribe` header whose angle-bracketed options are either `mailto:`
+//! or `https:` targets. This module parses those headers — following the
+//! [`UnsubscribeOption`] `Url`/`Email` distinction borrowed from meli — matches
+//! the `mailto:` addresses back to members, and produces a worklist of status
+//! changes so complaints detected outside Mailchimp can drive janitor actions.
+
+use crate::models::{MailchimpMember, MemberStatus};
+
+/// A single `List-Unsubscribe` option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnsubscribeOption {
+    /// An `https:` (or other URL-scheme) one-click/landing-page target.
+    Url(String),
+    /// A `mailto:` target address, with any query string stripped.
+    Email(String),
+}
+
+/// The unsubscribe-relevant fields of a single feedback message.
+#[derive(Debug, Clone, Default)]
+pub struct FeedbackMessage {
+    pub options: Vec<UnsubscribeOption>,
+    /// Whether the message advertises RFC 8058 one-click unsubscribe. This is a
+    /// transport capability, not a complaint — it still maps to an ordinary
+    /// unsubscribe.
+    pub one_click: bool,
+    /// Whether the message is an abuse/spam complaint (an RFC 5965 ARF feedback
+    /// report), which maps to Mailchimp's `cleaned` status.
+    pub complaint: bool,
+}
+
+/// A member whose status should change as a result of inbound feedback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorklistItem {
+    pub member_id: String,
+    pub email_address: String,
+    pub new_status: MemberStatus,
+}
+
+/// Parses the comma-separated, angle-bracketed options of a `List-Unsubscribe`
+/// header value.
+pub fn parse_list_unsubscribe(value: &str) -> Vec<UnsubscribeOption> {
+    let mut options = vec![];
+    for part in value.split(',') {
+        let part = part.trim();
+        let target = part
+            .strip_prefix('<')
+            .and_then(|p| p.strip_suffix('>'))
+            .unwrap_or(part)
+            .trim();
+        if let Some(rest) = target.strip_prefix("mailto:") {
+            let address = rest.split('?').next().unwrap_or(rest).trim();
+            if !address.is_empty() {
+                options.push(UnsubscribeOption::Email(address.to_string()));
+            }
+        } else if !target.is_empty() {
+            options.push(UnsubscribeOption::Url(target.to_string()));
+        }
+    }
+    options
+}
+
+/// Parses a single feedback message (header block) into its
+/// [`FeedbackMessage`].
+pub fn parse_message(raw: &str) -> FeedbackMessage {
+    let mut message = FeedbackMessage::default();
+    for (name, value) in unfold_headers(raw) {
+        let name = name.to_ascii_lowercase();
+        if name == "list-unsubscribe" {
+            message.options.extend(parse_list_unsubscribe(&value));
+        } else if name == "list-unsubscribe-post" {
+            message.one_click = value.to_ascii_lowercase().contains("one-click");
+        } else if name == "feedback-type" {
+            // ARF machine-readable part: `abuse` and `fraud` signal a
+            // complaint rather than an ordinary unsubscribe; other types do not.
+            let value = value.to_ascii_lowercase();
+            message.complaint = value == "abuse" || value == "fraud";
+        } else if name == "content-type" {
+            message.complaint |= value.to_ascii_lowercase().contains("feedback-report");
+        }
+    }
+    message
+}
+
+/// Splits an mbox mailbox into its messages and parses each one.
+pub fn parse_mailbox(raw: &str) -> Vec<FeedbackMessage> {
+    let mut messages = vec![];
+    let mut current = String::new();
+    for line in raw.lines() {
+        if line.starts_with("From ") && !current.is_empty() {
+            messages.push(parse_message(&current));
+            current.clear();
+        }
+        current.push_str(line);
+        current.push('\n');
+    }
+    if !current.trim().is_empty() {
+        messages.push(parse_message(&current));
+    }
+    messages
+}
+
+/// Reconciles feedback messages against the audience, producing one worklist
+/// item per matched member.
+///
+/// Complaint feedback (an ARF abuse/spam report) flips a member to
+/// [`MemberStatus::Cleaned`]; ordinary unsubscribe feedback — including RFC
+/// 8058 one-click — flips them to [`MemberStatus::Unsubscribed`].
+pub fn reconcile(messages: &[FeedbackMessage], members: &[MailchimpMember]) -> Vec<WorklistItem> {
+    let mut worklist: Vec<WorklistItem> = vec![];
+
+    for message in messages {
+        let new_status = if message.complaint {
+            MemberStatus::Cleaned
+        } else {
+            MemberStatus::Unsubscribed
+        };
+
+        for option in &message.options {
+            let UnsubscribeOption::Email(address) = option else {
+                continue;
+            };
+            for member in members {
+                if member.email_address.trim().eq_ignore_ascii_case(address) {
+                    let already = worklist
+                        .iter()
+                        .any(|item| item.member_id == member.id);
+                    if !already {
+                        worklist.push(WorklistItem {
+                            member_id: member.id.clone(),
+                            email_address: member.email_address.trim().to_string(),
+                            new_status: new_status.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    worklist
+}
+
+/// Unfolds the headers of a message into `(name, value)` pairs, stopping at the
+/// blank line that separates headers from the body.
+fn unfold_headers(raw: &str) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = vec![];
+    for line in raw.lines() {
+        if line.is_empty() {
+            break;
+        }
+        if line.starts_with(' ') || line.starts_with('\t') {
+            // Continuation of the previous header's folded value.
+            if let Some(last) = headers.last_mut() {
+                last.1.push(' ');
+                last.1.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(email: &str) -> MailchimpMember {
+        MailchimpMember {
+            id: "member-1".to_string(),
+            email_address: email.to_string(),
+            ..crate::models::sample_member()
+        }
+    }
+
+    #[test]
+    fn test_parse_multi_option_header_strips_mailto_query() {
+        let options = parse_list_unsubscribe(
+            "<mailto:unsub@example.com?subject=unsubscribe>, <https://example.com/u/123>",
+        );
+        assert_eq!(
+            options,
+            vec![
+                UnsubscribeOption::Email("unsub@example.com".to_string()),
+                UnsubscribeOption::Url("https://example.com/u/123".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_message_unfolds_headers_and_detects_one_click() {
+        let raw = "From: sender@example.com\n\
+                   List-Unsubscribe: <mailto:unsub@example.com>,\n\
+                   \t<https://example.com/u/123>\n\
+                   List-Unsubscribe-Post: List-Unsubscribe=One-Click\n\
+                   \n\
+                   body line that must be ignored\n";
+        let message = parse_message(raw);
+        assert!(message.one_click);
+        assert_eq!(
+            message.options,
+            vec![
+                UnsubscribeOption::Email("unsub@example.com".to_string()),
+                UnsubscribeOption::Url("https://example.com/u/123".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reconcile_maps_complaint_to_cleaned() {
+        let members = vec![member("unsub@example.com")];
+
+        let complaint = FeedbackMessage {
+            options: vec![UnsubscribeOption::Email("unsub@example.com".to_string())],
+            one_click: false,
+            complaint: true,
+        };
+        // One-click is a transport capability, not a complaint: ordinary unsub.
+        let one_click = FeedbackMessage {
+            options: vec![UnsubscribeOption::Email("unsub@example.com".to_string())],
+            one_click: true,
+            complaint: false,
+        };
+
+        let cleaned = reconcile(&[complaint], &members);
+        assert_eq!(cleaned.len(), 1);
+        assert_eq!(cleaned[0].new_status, MemberStatus::Cleaned);
+
+        let unsubscribed = reconcile(&[one_click], &members);
+        assert_eq!(unsubscribed.len(), 1);
+        assert_eq!(unsubscribed[0].new_status, MemberStatus::Unsubscribed);
+    }
+
+    #[test]
+    fn test_parse_message_detects_arf_complaint() {
+        let raw = "Content-Type: multipart/report; report-type=feedback-report\n\
+                   Feedback-Type: abuse\n\
+                   List-Unsubscribe: <mailto:unsub@example.com>\n\
+                   \n\
+                   body\n";
+        let message = parse_message(raw);
+        assert!(message.complaint);
+    }
+}
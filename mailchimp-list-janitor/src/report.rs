@@ -0,0 +1,85 @@
+//! Templated action reports and notification emails.
+//!
+//! A [`Reporter`] renders a human-readable summary of a cleanup run and,
+//! optionally, per-member notification messages. Templates are
+//! [`minijinja`]-backed and fall back to sane built-in defaults when the
+//! operator does not supply their own. The template context exposes the full
+//! [`MailchimpMember`] alongside the matched cleanup `reason`.
+
+use crate::models::MailchimpMember;
+use minijinja::{Environment, Value, context};
+use serde::Serialize;
+
+/// Default template used for the run report when none is configured.
+pub const DEFAULT_REPORT_TEMPLATE: &str = "Cleanup report: {{ entries | length }} member(s)\n\
+{% for entry in entries %}- {{ entry.member.email_address }} ({{ entry.member.full_name }}): {{ entry.reason }}\n{% endfor %}";
+
+/// Default template used for per-member notifications when none is configured.
+pub const DEFAULT_NOTIFICATION_TEMPLATE: &str =
+    "Hi {{ member.full_name }},\n\nYou were removed from our mailing list ({{ reason }}).\n";
+
+const REPORT: &str = "report";
+const NOTIFICATION: &str = "notification";
+
+/// A single member together with the reason it was selected for cleanup.
+#[derive(Debug, Clone, Serialize)]
+pub struct CleanupEntry<'a> {
+    pub member: &'a MailchimpMember,
+    pub reason: &'a str,
+}
+
+/// Renders reports and notifications from configurable templates.
+pub struct Reporter {
+    env: Environment<'static>,
+}
+
+impl Default for Reporter {
+    fn default() -> Self {
+        let mut env = Environment::new();
+        env.add_template_owned(REPORT, DEFAULT_REPORT_TEMPLATE.to_string())
+            .expect("default report template should parse");
+        env.add_template_owned(NOTIFICATION, DEFAULT_NOTIFICATION_TEMPLATE.to_string())
+            .expect("default notification template should parse");
+        Self { env }
+    }
+}
+
+impl Reporter {
+    /// Creates a reporter backed by the built-in default templates.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the report template.
+    pub fn with_report_template(mut self, template: impl Into<String>) -> Result<Self, minijinja::Error> {
+        self.env.add_template_owned(REPORT, template.into())?;
+        Ok(self)
+    }
+
+    /// Overrides the notification template.
+    pub fn with_notification_template(
+        mut self,
+        template: impl Into<String>,
+    ) -> Result<Self, minijinja::Error> {
+        self.env.add_template_owned(NOTIFICATION, template.into())?;
+        Ok(self)
+    }
+
+    /// Renders the run report for the given cleanup entries.
+    pub fn render_report(&self, entries: &[CleanupEntry]) -> Result<String, minijinja::Error> {
+        let entries = entries.iter().map(Value::from_serialize).collect::<Vec<_>>();
+        self.env.get_template(REPORT)?.render(context! { entries })
+    }
+
+    /// Renders the notification message for a single member.
+    pub fn render_notification(
+        &self,
+        member: &MailchimpMember,
+        reason: &str,
+    ) -> Result<String, minijinja::Error> {
+        let member = Value::from_serialize(member);
+        self.env
+            .get_template(NOTIFICATION)?
+            .render(context! { member, reason })
+    }
+}
@@ -0,0 +1,284 @@
+//! A declarative engine for deciding which members to keep, archive or delete.
+//!
+//! A [`RuleSet`] is a list of ordered [`Rule`]s, each a conjunction of
+//! [`Predicate`]s over a member's engagement metrics. The first rule whose
+//! predicates all match decides a member's fate; VIP members are kept by
+//! default. Rule sets are `serde`-deserializable so they can live in a config
+//! file.
+
+use crate::models::MailchimpMember;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Tolerance used when comparing a metric against a threshold for equality, so
+/// fractional metrics like `avg_open_rate` are matched robustly rather than
+/// with a brittle `f64` identity test.
+const EPSILON: f64 = 1e-9;
+
+/// The action a matching [`Rule`] assigns to a member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    Keep,
+    Archive,
+    Delete,
+}
+
+/// A member metric a [`Predicate`] can test.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    AvgOpenRate,
+    AvgClickRate,
+    MemberRating,
+    TotalRevenue,
+    NumberOfOrders,
+    /// Number of days since `last_changed`.
+    LastChangedDaysAgo,
+    /// Number of days since `timestamp_opt`.
+    TimestampOptDaysAgo,
+}
+
+/// The comparison applied between a [`Metric`] and a threshold.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Comparison {
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Eq,
+}
+
+/// A single `metric op value` test.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Predicate {
+    pub metric: Metric,
+    pub op: Comparison,
+    pub value: f64,
+}
+
+impl Predicate {
+    fn matches(&self, member: &MailchimpMember, now: DateTime<Utc>) -> bool {
+        let Some(actual) = metric_value(self.metric, member, now) else {
+            return false;
+        };
+        match self.op {
+            Comparison::Lt => actual < self.value,
+            Comparison::Lte => actual <= self.value,
+            Comparison::Gt => actual > self.value,
+            Comparison::Gte => actual >= self.value,
+            Comparison::Eq => (actual - self.value).abs() < EPSILON,
+        }
+    }
+}
+
+/// A named rule: a conjunction of predicates and the action to take when they
+/// all hold.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rule {
+    pub name: String,
+    pub action: Action,
+    #[serde(default)]
+    pub predicates: Vec<Predicate>,
+}
+
+impl Rule {
+    fn matches(&self, member: &MailchimpMember, now: DateTime<Utc>) -> bool {
+        self.predicates.iter().all(|p| p.matches(member, now))
+    }
+}
+
+fn default_skip_vip() -> bool {
+    true
+}
+
+/// An ordered collection of rules plus the VIP short-circuit toggle.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RuleSet {
+    #[serde(default = "default_skip_vip")]
+    pub skip_vip: bool,
+    pub rules: Vec<Rule>,
+}
+
+/// The outcome for a single member, carrying the rule that decided it.
+#[derive(Debug, Clone)]
+pub struct Decision<'a> {
+    pub member: &'a MailchimpMember,
+    pub action: Action,
+    /// The rule that matched, or `None` when the member was kept by default
+    /// (no rule matched, or the VIP short-circuit applied).
+    pub matched_rule: Option<String>,
+}
+
+/// The keep/archive/delete partition produced by evaluating a [`RuleSet`].
+#[derive(Debug, Default)]
+pub struct Partition<'a> {
+    pub keep: Vec<Decision<'a>>,
+    pub archive: Vec<Decision<'a>>,
+    pub delete: Vec<Decision<'a>>,
+}
+
+impl RuleSet {
+    /// Evaluates every member against the rules, partitioning them by action.
+    pub fn evaluate<'a>(&self, members: &'a [MailchimpMember]) -> Partition<'a> {
+        let now = Utc::now();
+        let mut partition = Partition::default();
+
+        for member in members {
+            let decision = self.decide(member, now);
+            match decision.action {
+                Action::Keep => partition.keep.push(decision),
+                Action::Archive => partition.archive.push(decision),
+                Action::Delete => partition.delete.push(decision),
+            }
+        }
+
+        partition
+    }
+
+    fn decide<'a>(&self, member: &'a MailchimpMember, now: DateTime<Utc>) -> Decision<'a> {
+        if self.skip_vip && member.vip {
+            return Decision {
+                member,
+                action: Action::Keep,
+                matched_rule: None,
+            };
+        }
+
+        for rule in &self.rules {
+            if rule.matches(member, now) {
+                return Decision {
+                    member,
+                    action: rule.action,
+                    matched_rule: Some(rule.name.clone()),
+                };
+            }
+        }
+
+        Decision {
+            member,
+            action: Action::Keep,
+            matched_rule: None,
+        }
+    }
+}
+
+fn metric_value(metric: Metric, member: &MailchimpMember, now: DateTime<Utc>) -> Option<f64> {
+    match metric {
+        Metric::AvgOpenRate => Some(member.stats.avg_open_rate),
+        Metric::AvgClickRate => Some(member.stats.avg_click_rate),
+        Metric::MemberRating => Some(member.member_rating as f64),
+        Metric::TotalRevenue => Some(
+            member
+                .stats
+                .ecommerce_data
+                .as_ref()
+                .map(|e| e.total_revenue)
+                .unwrap_or(0.0),
+        ),
+        Metric::NumberOfOrders => Some(
+            member
+                .stats
+                .ecommerce_data
+                .as_ref()
+                .map(|e| e.number_of_orders as f64)
+                .unwrap_or(0.0),
+        ),
+        Metric::LastChangedDaysAgo => days_ago(&member.last_changed, now),
+        Metric::TimestampOptDaysAgo => days_ago(&member.timestamp_opt, now),
+    }
+}
+
+fn days_ago(timestamp: &str, now: DateTime<Utc>) -> Option<f64> {
+    let parsed = DateTime::parse_from_rfc3339(timestamp).ok()?;
+    Some((now - parsed.with_timezone(&Utc)).num_seconds() as f64 / 86_400.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(vip: bool, last_changed: &str) -> MailchimpMember {
+        MailchimpMember {
+            vip,
+            last_changed: last_changed.to_string(),
+            ..crate::models::sample_member()
+        }
+    }
+
+    fn always() -> Predicate {
+        Predicate {
+            metric: Metric::MemberRating,
+            op: Comparison::Gte,
+            value: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_vip_short_circuits_to_keep() {
+        let rules = RuleSet {
+            skip_vip: true,
+            rules: vec![Rule {
+                name: "purge".into(),
+                action: Action::Delete,
+                predicates: vec![always()],
+            }],
+        };
+        let now = Utc::now();
+        let decision = rules.decide(&member(true, "2021-01-01T00:00:00+00:00"), now);
+        assert_eq!(decision.action, Action::Keep);
+        assert_eq!(decision.matched_rule, None);
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let rules = RuleSet {
+            skip_vip: true,
+            rules: vec![
+                Rule {
+                    name: "archive".into(),
+                    action: Action::Archive,
+                    predicates: vec![always()],
+                },
+                Rule {
+                    name: "delete".into(),
+                    action: Action::Delete,
+                    predicates: vec![always()],
+                },
+            ],
+        };
+        let now = Utc::now();
+        let decision = rules.decide(&member(false, "2021-01-01T00:00:00+00:00"), now);
+        assert_eq!(decision.action, Action::Archive);
+        assert_eq!(decision.matched_rule.as_deref(), Some("archive"));
+    }
+
+    #[test]
+    fn test_last_changed_days_ago_predicate() {
+        let rules = RuleSet {
+            skip_vip: true,
+            rules: vec![Rule {
+                name: "stale".into(),
+                action: Action::Archive,
+                predicates: vec![Predicate {
+                    metric: Metric::LastChangedDaysAgo,
+                    op: Comparison::Gt,
+                    value: 90.0,
+                }],
+            }],
+        };
+        // 100 days after the member's `last_changed`.
+        let now = DateTime::parse_from_rfc3339("2021-04-11T00:00:00+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let stale = rules.decide(&member(false, "2021-01-01T00:00:00+00:00"), now);
+        assert_eq!(stale.action, Action::Archive);
+
+        // A member changed only 10 days ago falls through to the default Keep.
+        let fresh = rules.decide(&member(false, "2021-04-01T00:00:00+00:00"), now);
+        assert_eq!(fresh.action, Action::Keep);
+        assert_eq!(fresh.matched_rule, None);
+    }
+}
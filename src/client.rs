@@ -4,6 +4,7 @@ use crate::{
 };
 use async_gen::gen;
 use futures_core::Stream;
+use reqwest::Url;
 use std::{sync::Arc, time::Duration};
 use tokio::task::JoinSet;
 
@@ -25,9 +26,9 @@ impl Default for MaxConcurrency {
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct Client {
-    base_url: String,
+    base_url: Url,
     list_id: String,
     api_key: String,
     http: reqwest::Client,
@@ -36,18 +37,28 @@ pub struct Client {
 }
 
 impl Client {
-    pub fn new<S: Into<String>>(base_url: S, list_id: S, api_key: S) -> Arc<Self> {
+    pub fn new<U: AsRef<str>, S: Into<String>>(base_url: U, list_id: S, api_key: S) -> Arc<Self> {
+        Self::try_new(base_url, list_id, api_key).unwrap()
+    }
+
+    /// Initializes a new client, validating the `base_url` at construction time.
+    pub fn try_new<U: AsRef<str>, S: Into<String>>(
+        base_url: U,
+        list_id: S,
+        api_key: S,
+    ) -> Result<Arc<Self>, Error> {
         let http = reqwest::Client::builder()
             .timeout(Duration::from_secs(10))
             .build()
             .unwrap();
-        Arc::new(Self {
-            base_url: base_url.into(),
+        Ok(Arc::new(Self {
+            base_url: Url::parse(base_url.as_ref())?,
             list_id: list_id.into(),
             api_key: api_key.into(),
             http,
-            ..Default::default()
-        })
+            max_concurrency: Default::default(),
+            page_size: Default::default(),
+        }))
     }
 
     /// Eagerly fetches all the ids of the unsubscribed users.
@@ -58,11 +69,12 @@ impl Client {
         let mut offset = 0;
         let mut unsubcribed_ids = vec![];
         loop {
+            let url = self.base_url.join(&format!(
+                "/3.0/lists/{}/members?status=unsubscribed&count={}&offset={}&sort_field=timestamp_signup&sort_dir=ASC",
+                self.list_id, self.page_size.0, offset
+            ))?;
             let resp = self.http
-              .get(&format!(
-                  "{}/3.0/lists/{}/members?status=unsubscribed&count={}&offset={}&sort_field=timestamp_signup&sort_dir=ASC",
-                  self.base_url, self.list_id, self.page_size.0, offset
-              ))
+              .get(url)
               .basic_auth("anystring", Some(&self.api_key))
               .send()
               .await?;
@@ -89,12 +101,13 @@ impl Client {
     }
 
     async fn archive_unsubscribed(&self, id: String) -> Result<String, ArchiveError> {
+        let url = self
+            .base_url
+            .join(&format!("/3.0/lists/{}/members/{}", self.list_id, id))
+            .map_err(|e| ArchiveError::InvalidUrl(id.clone(), e))?;
         let resp = self
             .http
-            .patch(&format!(
-                "{}/3.0/lists/{}/members/{}",
-                self.base_url, self.list_id, id
-            ))
+            .patch(url)
             .basic_auth("anystring", Some(&self.api_key))
             .header("Content-Type", "application/json")
             .body("{\"status\":\"cleaned\"}")
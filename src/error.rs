@@ -3,6 +3,8 @@ use tokio::task::JoinError;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
     #[error("{0}")]
     FetchIds(#[from] FetchIdsError),
     #[error("{0}")]
@@ -13,6 +15,8 @@ pub enum Error {
 
 #[derive(Debug, thiserror::Error)]
 pub enum FetchIdsError {
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
     #[error("Request error: {0}")]
     Request(#[from] reqwest::Error),
     #[error("Mailchimp error: {0}")]
@@ -21,6 +25,8 @@ pub enum FetchIdsError {
 
 #[derive(Debug, thiserror::Error)]
 pub enum ArchiveError {
+    #[error("Invalid URL while archiving user {0}: {1}")]
+    InvalidUrl(String, url::ParseError),
     #[error("Request error while archiving user {0}: {1}")]
     Request(String, reqwest::Error),
     #[error("Mailchimp error while archiving user {0}: {1}")]
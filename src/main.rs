@@ -8,7 +8,7 @@ async fn main() -> Result<(), anyhow::Error> {
     let base_url = env::var("MAILCHIMP_BASE_URL")?;
     let list_id = env::var("MAILCHIMP_LIST_ID")?;
 
-    let client = client::Client::new(&base_url, &list_id, &api_key);
+    let client = client::Client::try_new(&base_url, &list_id, &api_key)?;
     let gen = client.move_unsubscribed_to_archive().await?;
 
     gen.for_each(|res| async move {